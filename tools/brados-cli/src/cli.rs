@@ -28,6 +28,10 @@ pub enum Commands {
     Recipes(RecipesCmd),
     /// Ingredient operations
     Ingredients(IngredientsCmd),
+    /// Workout logging operations
+    Workouts(WorkoutsCmd),
+    /// Mesocycle lifecycle operations
+    Mesocycles(MesocyclesCmd),
 }
 
 #[derive(Parser, Debug)]
@@ -314,6 +318,107 @@ pub enum IngredientsAction {
     },
 }
 
+#[derive(Parser, Debug)]
+pub struct WorkoutsCmd {
+    #[command(subcommand)]
+    pub action: WorkoutsAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum WorkoutsAction {
+    /// Get today's workout (next pending or in-progress)
+    Today,
+    /// Get a workout with its sets grouped by exercise
+    Get {
+        /// Workout ID
+        id: String,
+    },
+    /// Start a workout
+    Start {
+        /// Workout ID
+        id: String,
+    },
+    /// Complete a workout
+    Complete {
+        /// Workout ID
+        id: String,
+    },
+    /// Skip a workout
+    Skip {
+        /// Workout ID
+        id: String,
+    },
+    /// List all sets for a workout
+    Sets {
+        /// Workout ID
+        id: String,
+    },
+    /// Log actual reps and weight for a set
+    LogSet {
+        /// Workout set ID
+        set_id: String,
+        /// Reps performed
+        #[arg(long)]
+        reps: u32,
+        /// Weight used
+        #[arg(long)]
+        weight: f64,
+    },
+    /// Skip a set
+    SkipSet {
+        /// Workout set ID
+        set_id: String,
+    },
+    /// Clear a logged or skipped set back to pending
+    UnlogSet {
+        /// Workout set ID
+        set_id: String,
+    },
+}
+
+#[derive(Parser, Debug)]
+pub struct MesocyclesCmd {
+    #[command(subcommand)]
+    pub action: MesocyclesAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum MesocyclesAction {
+    /// List all mesocycles
+    List,
+    /// Get the active mesocycle with week summaries
+    Active,
+    /// Get a mesocycle with week summaries
+    Get {
+        /// Mesocycle ID
+        id: String,
+    },
+    /// Create a new mesocycle from a plan
+    Create {
+        /// Plan ID to build the mesocycle from
+        #[arg(long)]
+        plan_id: String,
+        /// Start date in YYYY-MM-DD format
+        #[arg(long)]
+        start_date: String,
+    },
+    /// Start a pending mesocycle
+    Start {
+        /// Mesocycle ID
+        id: String,
+    },
+    /// Complete an active mesocycle
+    Complete {
+        /// Mesocycle ID
+        id: String,
+    },
+    /// Cancel a mesocycle
+    Cancel {
+        /// Mesocycle ID
+        id: String,
+    },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -719,4 +824,79 @@ mod tests {
             _ => panic!("expected Meals"),
         }
     }
+
+    #[test]
+    fn parse_workouts_today() {
+        let cli = parse(&["workouts", "today"]);
+        assert!(matches!(cli.command, Commands::Workouts(ref cmd)
+            if matches!(cmd.action, WorkoutsAction::Today)));
+    }
+
+    #[test]
+    fn parse_workouts_log_set() {
+        let cli = parse(&[
+            "workouts", "log-set", "set_1", "--reps", "8", "--weight", "137.5",
+        ]);
+        match &cli.command {
+            Commands::Workouts(cmd) => match &cmd.action {
+                WorkoutsAction::LogSet {
+                    set_id,
+                    reps,
+                    weight,
+                } => {
+                    assert_eq!(set_id, "set_1");
+                    assert_eq!(*reps, 8);
+                    assert_eq!(*weight, 137.5);
+                }
+                _ => panic!("expected LogSet"),
+            },
+            _ => panic!("expected Workouts"),
+        }
+    }
+
+    #[test]
+    fn parse_workouts_unlog_set() {
+        let cli = parse(&["workouts", "unlog-set", "set_1"]);
+        match &cli.command {
+            Commands::Workouts(cmd) => match &cmd.action {
+                WorkoutsAction::UnlogSet { set_id } => {
+                    assert_eq!(set_id, "set_1");
+                }
+                _ => panic!("expected UnlogSet"),
+            },
+            _ => panic!("expected Workouts"),
+        }
+    }
+
+    #[test]
+    fn parse_mesocycles_active() {
+        let cli = parse(&["mesocycles", "active"]);
+        assert!(matches!(cli.command, Commands::Mesocycles(ref cmd)
+            if matches!(cmd.action, MesocyclesAction::Active)));
+    }
+
+    #[test]
+    fn parse_mesocycles_create() {
+        let cli = parse(&[
+            "mesocycles",
+            "create",
+            "--plan-id",
+            "plan_1",
+            "--start-date",
+            "2026-03-02",
+        ]);
+        match &cli.command {
+            Commands::Mesocycles(cmd) => match &cmd.action {
+                MesocyclesAction::Create {
+                    plan_id,
+                    start_date,
+                } => {
+                    assert_eq!(plan_id, "plan_1");
+                    assert_eq!(start_date, "2026-03-02");
+                }
+                _ => panic!("expected Create"),
+            },
+            _ => panic!("expected Mesocycles"),
+        }
+    }
 }
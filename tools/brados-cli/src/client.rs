@@ -122,6 +122,24 @@ impl ApiClient {
         Ok(body)
     }
 
+    /// Perform a PUT request with no body.
+    pub fn put_empty(&self, path: &str) -> Result<serde_json::Value, CliError> {
+        let url = self.url_for(path);
+        let response = ureq::put(&url)
+            .header("x-firebase-appcheck", &self.appcheck_token)
+            .content_type("application/json")
+            .send_empty()
+            .map_err(|e| CliError::Http(e.to_string()))?;
+
+        let body: serde_json::Value = response
+            .into_body()
+            .read_json::<serde_json::Value>()
+            .map_err(|e| CliError::Deserialize(e.to_string()))?;
+
+        check_api_error(&body)?;
+        Ok(body)
+    }
+
     /// Perform a DELETE request.
     pub fn delete(&self, path: &str) -> Result<serde_json::Value, CliError> {
         let url = self.url_for(path);
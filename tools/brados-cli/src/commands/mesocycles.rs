@@ -0,0 +1,122 @@
+use crate::client::{extract_data, ApiClient};
+use crate::error::CliError;
+use crate::output::print_success;
+use crate::types::{Mesocycle, MesocycleWithDetails};
+
+/// Validate that a date is in YYYY-MM-DD format.
+pub(crate) fn validate_date(arg_name: &str, date: &str) -> Result<(), CliError> {
+    let bytes = date.as_bytes();
+    let well_formed = bytes.len() == 10
+        && bytes.iter().enumerate().all(|(idx, byte)| match idx {
+            4 | 7 => *byte == b'-',
+            _ => byte.is_ascii_digit(),
+        });
+    if !well_formed {
+        return Err(CliError::Deserialize(format!(
+            "invalid {arg_name} '{date}'. Expected YYYY-MM-DD"
+        )));
+    }
+    Ok(())
+}
+
+fn lifecycle_path(id: &str, action: &str) -> String {
+    format!("/mesocycles/{id}/{action}")
+}
+
+/// List all mesocycles.
+pub fn list(client: &ApiClient) -> Result<(), CliError> {
+    let body = client.get("/mesocycles")?;
+    let data: Vec<Mesocycle> = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Get the active mesocycle with week summaries.
+pub fn active(client: &ApiClient) -> Result<(), CliError> {
+    let body = client.get("/mesocycles/active")?;
+    let data: Option<MesocycleWithDetails> = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Get a mesocycle by ID with week summaries.
+pub fn get(client: &ApiClient, id: &str) -> Result<(), CliError> {
+    let body = client.get(&format!("/mesocycles/{id}"))?;
+    let data: MesocycleWithDetails = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Create a new mesocycle from a plan.
+pub fn create(client: &ApiClient, plan_id: &str, start_date: &str) -> Result<(), CliError> {
+    validate_date("start_date", start_date)?;
+
+    let payload = serde_json::json!({
+        "plan_id": plan_id,
+        "start_date": start_date,
+    });
+
+    let body = client.post_json("/mesocycles", &payload)?;
+    let data: Mesocycle = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Start a pending mesocycle.
+pub fn start(client: &ApiClient, id: &str) -> Result<(), CliError> {
+    let body = client.put_empty(&lifecycle_path(id, "start"))?;
+    let data: Mesocycle = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Complete an active mesocycle.
+pub fn complete(client: &ApiClient, id: &str) -> Result<(), CliError> {
+    let body = client.put_empty(&lifecycle_path(id, "complete"))?;
+    let data: Mesocycle = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Cancel a mesocycle.
+pub fn cancel(client: &ApiClient, id: &str) -> Result<(), CliError> {
+    let body = client.put_empty(&lifecycle_path(id, "cancel"))?;
+    let data: Mesocycle = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lifecycle_path_uses_put_action_routes() {
+        assert_eq!(
+            lifecycle_path("meso_1", "start"),
+            "/mesocycles/meso_1/start"
+        );
+        assert_eq!(
+            lifecycle_path("meso_1", "cancel"),
+            "/mesocycles/meso_1/cancel"
+        );
+    }
+
+    #[test]
+    fn valid_date_passes() {
+        assert!(validate_date("start_date", "2026-03-02").is_ok());
+    }
+
+    #[test]
+    fn invalid_date_fails() {
+        for bad in ["2026-3-2", "03/02/2026", "2026-03-02T00:00", ""] {
+            match validate_date("start_date", bad) {
+                Err(CliError::Deserialize(msg)) => {
+                    assert!(msg.contains("invalid start_date"));
+                    assert!(msg.contains("YYYY-MM-DD"));
+                }
+                _ => panic!("expected Deserialize error for {bad:?}"),
+            }
+        }
+    }
+}
@@ -2,5 +2,7 @@ pub mod health_sync;
 pub mod ingredients;
 pub mod mealplan;
 pub mod meals;
+pub mod mesocycles;
 pub mod recipes;
 pub mod shoppinglist;
+pub mod workouts;
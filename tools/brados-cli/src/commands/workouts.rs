@@ -0,0 +1,119 @@
+use crate::client::{extract_data, ApiClient};
+use crate::error::CliError;
+use crate::output::print_success;
+use crate::types::{Workout, WorkoutSet, WorkoutWithExercises};
+
+fn workout_action_path(id: &str, action: &str) -> String {
+    format!("/workouts/{id}/{action}")
+}
+
+fn set_action_path(set_id: &str, action: &str) -> String {
+    format!("/workout-sets/{set_id}/{action}")
+}
+
+pub(crate) fn build_log_set_payload(reps: u32, weight: f64) -> serde_json::Value {
+    serde_json::json!({
+        "actual_reps": reps,
+        "actual_weight": weight,
+    })
+}
+
+/// Get today's workout (the next pending or in-progress workout).
+pub fn today(client: &ApiClient) -> Result<(), CliError> {
+    let body = client.get("/workouts/today")?;
+    let data: Option<WorkoutWithExercises> = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Get a workout by ID with its sets grouped by exercise.
+pub fn get(client: &ApiClient, id: &str) -> Result<(), CliError> {
+    let body = client.get(&format!("/workouts/{id}"))?;
+    let data: WorkoutWithExercises = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Start a workout.
+pub fn start(client: &ApiClient, id: &str) -> Result<(), CliError> {
+    let body = client.put_empty(&workout_action_path(id, "start"))?;
+    let data: Workout = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Complete a workout.
+pub fn complete(client: &ApiClient, id: &str) -> Result<(), CliError> {
+    let body = client.put_empty(&workout_action_path(id, "complete"))?;
+    let data: Workout = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Skip a workout.
+pub fn skip(client: &ApiClient, id: &str) -> Result<(), CliError> {
+    let body = client.put_empty(&workout_action_path(id, "skip"))?;
+    let data: Workout = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// List all sets for a workout.
+pub fn sets(client: &ApiClient, id: &str) -> Result<(), CliError> {
+    let body = client.get(&workout_action_path(id, "sets"))?;
+    let data: Vec<WorkoutSet> = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Log the actual reps and weight for a set.
+pub fn log_set(client: &ApiClient, set_id: &str, reps: u32, weight: f64) -> Result<(), CliError> {
+    let payload = build_log_set_payload(reps, weight);
+    let body = client.put_json(&set_action_path(set_id, "log"), &payload)?;
+    let data: WorkoutSet = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Skip a set.
+pub fn skip_set(client: &ApiClient, set_id: &str) -> Result<(), CliError> {
+    let body = client.put_empty(&set_action_path(set_id, "skip"))?;
+    let data: WorkoutSet = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+/// Clear a logged set back to pending.
+pub fn unlog_set(client: &ApiClient, set_id: &str) -> Result<(), CliError> {
+    let body = client.put_empty(&set_action_path(set_id, "unlog"))?;
+    let data: WorkoutSet = extract_data(body)?;
+    print_success(&data);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn workout_actions_use_workout_routes() {
+        assert_eq!(workout_action_path("w_1", "start"), "/workouts/w_1/start");
+        assert_eq!(workout_action_path("w_1", "sets"), "/workouts/w_1/sets");
+    }
+
+    #[test]
+    fn set_actions_use_workout_sets_routes() {
+        assert_eq!(set_action_path("set_1", "log"), "/workout-sets/set_1/log");
+        assert_eq!(
+            set_action_path("set_1", "unlog"),
+            "/workout-sets/set_1/unlog"
+        );
+    }
+
+    #[test]
+    fn build_log_set_payload_includes_reps_and_weight() {
+        let payload = build_log_set_payload(8, 137.5);
+        assert_eq!(payload["actual_reps"], 8);
+        assert_eq!(payload["actual_weight"], 137.5);
+    }
+}
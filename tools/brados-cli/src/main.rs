@@ -9,8 +9,8 @@ use clap::Parser;
 use std::process;
 
 use cli::{
-    Cli, Commands, HealthSyncAction, IngredientsAction, MealplanAction, MealsAction, RecipesAction,
-    ShoppinglistAction,
+    Cli, Commands, HealthSyncAction, IngredientsAction, MealplanAction, MealsAction,
+    MesocyclesAction, RecipesAction, ShoppinglistAction, WorkoutsAction,
 };
 use client::ApiClient;
 use output::print_error;
@@ -198,6 +198,39 @@ fn run() -> Result<(), error::CliError> {
             }
             IngredientsAction::Delete { id } => commands::ingredients::delete(&client, &id)?,
         },
+        Commands::Workouts(cmd) => match cmd.action {
+            WorkoutsAction::Today => commands::workouts::today(&client)?,
+            WorkoutsAction::Get { id } => commands::workouts::get(&client, &id)?,
+            WorkoutsAction::Start { id } => commands::workouts::start(&client, &id)?,
+            WorkoutsAction::Complete { id } => commands::workouts::complete(&client, &id)?,
+            WorkoutsAction::Skip { id } => commands::workouts::skip(&client, &id)?,
+            WorkoutsAction::Sets { id } => commands::workouts::sets(&client, &id)?,
+            WorkoutsAction::LogSet {
+                set_id,
+                reps,
+                weight,
+            } => {
+                commands::workouts::log_set(&client, &set_id, reps, weight)?;
+            }
+            WorkoutsAction::SkipSet { set_id } => commands::workouts::skip_set(&client, &set_id)?,
+            WorkoutsAction::UnlogSet { set_id } => {
+                commands::workouts::unlog_set(&client, &set_id)?;
+            }
+        },
+        Commands::Mesocycles(cmd) => match cmd.action {
+            MesocyclesAction::List => commands::mesocycles::list(&client)?,
+            MesocyclesAction::Active => commands::mesocycles::active(&client)?,
+            MesocyclesAction::Get { id } => commands::mesocycles::get(&client, &id)?,
+            MesocyclesAction::Create {
+                plan_id,
+                start_date,
+            } => {
+                commands::mesocycles::create(&client, &plan_id, &start_date)?;
+            }
+            MesocyclesAction::Start { id } => commands::mesocycles::start(&client, &id)?,
+            MesocyclesAction::Complete { id } => commands::mesocycles::complete(&client, &id)?,
+            MesocyclesAction::Cancel { id } => commands::mesocycles::cancel(&client, &id)?,
+        },
     }
 
     Ok(())
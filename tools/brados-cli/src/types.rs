@@ -200,6 +200,150 @@ pub const VALID_STORE_SECTIONS: &[&str] = &[
     "Pantry Staples",
 ];
 
+// Workout types
+
+/// Workout status enum matching the API's snake_case convention.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkoutStatus {
+    Pending,
+    InProgress,
+    Completed,
+    Skipped,
+}
+
+/// Workout set status enum matching the API's lowercase convention.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkoutSetStatus {
+    Pending,
+    Completed,
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workout {
+    pub id: String,
+    pub mesocycle_id: String,
+    pub plan_day_id: String,
+    pub week_number: u32,
+    pub scheduled_date: String,
+    pub status: WorkoutStatus,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkoutSet {
+    pub id: String,
+    pub workout_id: String,
+    pub exercise_id: String,
+    pub set_number: u32,
+    pub target_reps: u32,
+    pub target_weight: f64,
+    pub actual_reps: Option<u32>,
+    pub actual_weight: Option<f64>,
+    pub status: WorkoutSetStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarmupSet {
+    pub warmup_number: u32,
+    pub target_weight: f64,
+    pub target_reps: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkoutExercise {
+    pub exercise_id: String,
+    pub exercise_name: String,
+    pub sets: Vec<WorkoutSet>,
+    pub total_sets: u32,
+    pub completed_sets: u32,
+    #[serde(default)]
+    pub warmup_sets: Vec<WarmupSet>,
+    pub rest_seconds: u32,
+}
+
+/// A workout with all of its sets grouped by exercise.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkoutWithExercises {
+    pub id: String,
+    pub mesocycle_id: String,
+    pub plan_day_id: String,
+    pub week_number: u32,
+    pub scheduled_date: String,
+    pub status: WorkoutStatus,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub plan_day_name: String,
+    pub exercises: Vec<WorkoutExercise>,
+}
+
+// Mesocycle types
+
+/// Mesocycle status enum matching the API's lowercase convention.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MesocycleStatus {
+    Pending,
+    Active,
+    Completed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mesocycle {
+    pub id: String,
+    pub plan_id: String,
+    pub start_date: String,
+    pub current_week: u32,
+    pub status: MesocycleStatus,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkoutSummary {
+    pub id: String,
+    pub plan_day_id: String,
+    pub plan_day_name: String,
+    pub day_of_week: u8,
+    pub week_number: u32,
+    pub scheduled_date: String,
+    pub status: WorkoutStatus,
+    pub completed_at: Option<String>,
+    pub exercise_count: u32,
+    pub set_count: u32,
+    pub completed_set_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeekSummary {
+    pub week_number: u32,
+    pub is_deload: bool,
+    pub workouts: Vec<WorkoutSummary>,
+    pub total_workouts: u32,
+    pub completed_workouts: u32,
+    pub skipped_workouts: u32,
+}
+
+/// A mesocycle with its plan name and per-week workout summaries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MesocycleWithDetails {
+    pub id: String,
+    pub plan_id: String,
+    pub start_date: String,
+    pub current_week: u32,
+    pub status: MesocycleStatus,
+    pub created_at: String,
+    pub updated_at: String,
+    pub plan_name: String,
+    pub weeks: Vec<WeekSummary>,
+    pub total_workouts: u32,
+    pub completed_workouts: u32,
+}
+
 // API envelope types
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -472,6 +616,123 @@ mod tests {
         assert_eq!(ingredient.store_section, "Produce");
     }
 
+    #[test]
+    fn deserialize_workout_with_exercises() {
+        let json = r#"{
+            "id": "workout_1",
+            "mesocycle_id": "meso_1",
+            "plan_day_id": "day_1",
+            "week_number": 2,
+            "scheduled_date": "2026-03-02",
+            "status": "in_progress",
+            "started_at": "2026-03-02T12:00:00.000Z",
+            "completed_at": null,
+            "plan_day_name": "Push",
+            "exercises": [
+                {
+                    "exercise_id": "ex_1",
+                    "exercise_name": "Bench Press",
+                    "sets": [
+                        {
+                            "id": "set_1",
+                            "workout_id": "workout_1",
+                            "exercise_id": "ex_1",
+                            "set_number": 1,
+                            "target_reps": 8,
+                            "target_weight": 135.0,
+                            "actual_reps": 9,
+                            "actual_weight": 135.0,
+                            "status": "completed"
+                        },
+                        {
+                            "id": "set_2",
+                            "workout_id": "workout_1",
+                            "exercise_id": "ex_1",
+                            "set_number": 2,
+                            "target_reps": 8,
+                            "target_weight": 135.0,
+                            "actual_reps": null,
+                            "actual_weight": null,
+                            "status": "pending"
+                        }
+                    ],
+                    "total_sets": 2,
+                    "completed_sets": 1,
+                    "warmup_sets": [
+                        {"warmup_number": 1, "target_weight": 65.0, "target_reps": 10}
+                    ],
+                    "rest_seconds": 90
+                }
+            ]
+        }"#;
+        let workout: WorkoutWithExercises = serde_json::from_str(json).unwrap();
+        assert_eq!(workout.status, WorkoutStatus::InProgress);
+        assert_eq!(workout.plan_day_name, "Push");
+        assert!(workout.completed_at.is_none());
+        let exercise = &workout.exercises[0];
+        assert_eq!(exercise.sets.len(), 2);
+        assert_eq!(exercise.sets[0].status, WorkoutSetStatus::Completed);
+        assert_eq!(exercise.sets[0].actual_reps, Some(9));
+        assert_eq!(exercise.sets[1].actual_weight, None);
+        assert_eq!(exercise.warmup_sets[0].target_reps, 10);
+    }
+
+    #[test]
+    fn workout_status_serializes_as_snake_case() {
+        let val = serde_json::to_string(&WorkoutStatus::InProgress).unwrap();
+        assert_eq!(val, r#""in_progress""#);
+        let val = serde_json::to_string(&WorkoutSetStatus::Skipped).unwrap();
+        assert_eq!(val, r#""skipped""#);
+    }
+
+    #[test]
+    fn deserialize_mesocycle_with_details() {
+        let json = r#"{
+            "id": "meso_1",
+            "plan_id": "plan_1",
+            "start_date": "2026-03-02",
+            "current_week": 3,
+            "status": "active",
+            "created_at": "2026-03-01T00:00:00Z",
+            "updated_at": "2026-03-01T00:00:00Z",
+            "plan_name": "Upper/Lower",
+            "weeks": [
+                {
+                    "week_number": 1,
+                    "is_deload": false,
+                    "workouts": [
+                        {
+                            "id": "workout_1",
+                            "plan_day_id": "day_1",
+                            "plan_day_name": "Upper",
+                            "day_of_week": 1,
+                            "week_number": 1,
+                            "scheduled_date": "2026-03-02",
+                            "status": "completed",
+                            "completed_at": "2026-03-02T13:00:00Z",
+                            "exercise_count": 4,
+                            "set_count": 12,
+                            "completed_set_count": 12
+                        }
+                    ],
+                    "total_workouts": 1,
+                    "completed_workouts": 1,
+                    "skipped_workouts": 0
+                }
+            ],
+            "total_workouts": 14,
+            "completed_workouts": 5
+        }"#;
+        let mesocycle: MesocycleWithDetails = serde_json::from_str(json).unwrap();
+        assert_eq!(mesocycle.status, MesocycleStatus::Active);
+        assert_eq!(mesocycle.current_week, 3);
+        assert_eq!(
+            mesocycle.weeks[0].workouts[0].status,
+            WorkoutStatus::Completed
+        );
+        assert_eq!(mesocycle.completed_workouts, 5);
+    }
+
     #[test]
     fn meal_type_serializes_as_lowercase() {
         let val = serde_json::to_string(&MealType::Breakfast).unwrap();